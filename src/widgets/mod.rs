@@ -15,6 +15,10 @@
 
 // Declare the widget modules
 pub mod scroll_area;
+pub mod scrollback_area;
+pub mod virtual_scroll_area;
 
 // Use some of it
-pub use scroll_area::{ScrollArea, StatefulScrollArea};
+pub use scroll_area::{ScrollArea, ScrollbarGlyphs, Scrollbars, StatefulScrollArea};
+pub use scrollback_area::ScrollbackArea;
+pub use virtual_scroll_area::{VirtualScrollArea, VirtualWidget};
@@ -14,6 +14,7 @@
 
 use std::cmp::min;
 
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::widgets::{StatefulWidget, Widget};
@@ -24,35 +25,208 @@ use ratatui::widgets::{StatefulWidget, Widget};
 ///
 /// # Arguments
 /// - `scroll`: The amount of scrolling to apply.
-/// - `outer`: The size of the _outer_ area (i.e., visible area).
+/// - `outer`: The size of the real, full outer area the widget was asked to render to. This is
+///   used purely to compute `outer_buf`'s row stride — it must be the Rect the caller actually
+///   received, never a narrowed-down sub-area, or every row past the first will be written at the
+///   wrong offset.
+/// - `window`: The (possibly narrower, e.g. with scrollbar space reserved) sub-`Rect` of `outer`
+///   that content should actually be copied into.
 /// - `inner`: The size of the _inner_ area (i.e., total area).
 /// - `inner_buf`: The rendered inside area, part of which to copy to the `outer_buf`.
 /// - `outer_buf`: The outside area to copy a smaller part of the `inner_buf` to.
-fn scroll(scroll: (u16, u16), outer: Rect, inner: Rect, inner_buf: &Buffer, outer_buf: &mut Buffer) {
-    // Next, decide which part of the inner window to copy
-    let pos: (u16, u16) = (min(scroll.0, outer.width), min(scroll.1, outer.height));
-    let cut: Rect = Rect::new(
-        pos.0,
-        pos.1,
-        if inner.width >= outer.width { outer.width - pos.0 } else { inner.width },
-        if inner.height >= outer.height { outer.height - pos.1 } else { inner.height },
-    );
-
-    // Then we copy that part into the output buffer (with the appropriate offsets)
+pub(crate) fn scroll(scroll: (u16, u16), outer: Rect, window: Rect, inner: Rect, inner_buf: &Buffer, outer_buf: &mut Buffer) {
+    // Next, decide which part of the inner window to copy. `pos` is clamped to `inner` so it can
+    // never be used to index past the inner buffer below.
+    let pos: (u16, u16) = (min(scroll.0, inner.width), min(scroll.1, inner.height));
+    let cut: Rect = Rect::new(pos.0, pos.1, min(window.width, inner.width - pos.0), min(window.height, inner.height - pos.1));
+
+    // Then we copy that part into the output buffer (with the appropriate offsets), reading
+    // `inner_buf` starting at `pos` rather than at its origin, so scrolling actually reveals
+    // different content instead of merely shrinking the copied rectangle. Note that the stride
+    // used to index `outer_buf` is always `outer.width` (the real buffer width), while the
+    // written-to coordinates are based on `window` (which may be narrower than `outer`).
     for y in 0..cut.height {
-        let outer_y: u16 = outer.y + y;
+        let outer_y: u16 = window.y + y;
+        let inner_y: u16 = cut.y + y;
         for x in 0..cut.width {
-            let outer_x: u16 = outer.x + x;
-            outer_buf.content[(outer_y * outer.width + outer_x) as usize] = inner_buf.content[(y * inner.width + x) as usize].clone();
+            let outer_x: u16 = window.x + x;
+            let inner_x: u16 = cut.x + x;
+            outer_buf.content[(outer_y * outer.width + outer_x) as usize] = inner_buf.content[(inner_y * inner.width + inner_x) as usize].clone();
         }
     }
 }
 
+/// Computes the length and offset of a scrollbar's thumb.
+///
+/// Mirrors ratatui's [`ScrollbarState`](https://docs.rs/ratatui/latest/ratatui/widgets/struct.ScrollbarState.html)
+/// model: the thumb's length is proportional to how much of the content is visible, and its
+/// offset is proportional to how far the content has been scrolled.
+///
+/// # Arguments
+/// - `outer_len`: The length (width or height) of the visible track, in cells.
+/// - `inner_len`: The length (width or height) of the total content being scrolled.
+/// - `pos`: The current scroll offset along this axis.
+///
+/// # Returns
+/// A `(thumb_len, thumb_pos)` pair, both given in cells along the track.
+pub(crate) fn thumb_metrics(outer_len: u16, inner_len: u16, pos: u16) -> (u16, u16) {
+    if outer_len == 0 || inner_len == 0 {
+        return (0, 0);
+    }
+    let thumb_len: u16 = ((outer_len as u32 * outer_len as u32) / inner_len as u32).max(1).min(outer_len as u32) as u16;
+    let travel: u16 = outer_len.saturating_sub(thumb_len);
+    let range: u16 = inner_len.saturating_sub(outer_len).max(1);
+    let thumb_pos: u16 = ((min(pos, range) as u32 * travel as u32) / range as u32) as u16;
+    (thumb_len, thumb_pos)
+}
+
+/// Draws a single scrollbar track (its thumb, and its arrow ends) into `buf`.
+///
+/// # Arguments
+/// - `glyphs`: The characters to use for the track, the thumb, and (if enabled) the arrow ends.
+/// - `outer`: The full outer area of the widget being scrolled (used to compute the buffer stride,
+///   see [`scroll()`]).
+/// - `area`: The single-cell-wide (or tall) strip the scrollbar occupies.
+/// - `vertical`: Whether this scrollbar runs top-to-bottom (`true`) or left-to-right (`false`).
+/// - `thumb_len`: The length of the thumb, in cells, as computed by [`thumb_metrics()`].
+/// - `thumb_pos`: The offset of the thumb from the start of `area`, in cells.
+/// - `buf`: The buffer to draw into.
+pub(crate) fn render_scrollbar(glyphs: ScrollbarGlyphs, outer: Rect, area: Rect, vertical: bool, thumb_len: u16, thumb_pos: u16, buf: &mut Buffer) {
+    let len: u16 = if vertical { area.height } else { area.width };
+    let (start_arrow, end_arrow) = if vertical { (glyphs.arrow_up, glyphs.arrow_down) } else { (glyphs.arrow_left, glyphs.arrow_right) };
+    for i in 0..len {
+        let symbol: char = if glyphs.show_arrows && len > 1 && i == 0 {
+            start_arrow
+        } else if glyphs.show_arrows && len > 1 && i == len - 1 {
+            end_arrow
+        } else if i >= thumb_pos && i < thumb_pos + thumb_len {
+            glyphs.thumb
+        } else {
+            glyphs.track
+        };
+        let (x, y) = if vertical { (area.x, area.y + i) } else { (area.x + i, area.y) };
+        buf.content[(y * outer.width + x) as usize].set_char(symbol);
+    }
+}
+
+/// Carves the space reserved for scrollbars out of the outer area, leaving the actual content area.
+///
+/// # Arguments
+/// - `outer`: The full outer area a [`ScrollArea`] was asked to render to.
+/// - `scrollbars`: Which scrollbar(s) are enabled, and thus how much space to reserve.
+///
+/// # Returns
+/// The sub-`Rect` of `outer` that the content should be scrolled into.
+pub(crate) fn reserve_scrollbars(outer: Rect, scrollbars: Scrollbars) -> Rect {
+    Rect::new(
+        outer.x,
+        outer.y,
+        if scrollbars.contains(Scrollbars::VERTICAL) { outer.width.saturating_sub(1) } else { outer.width },
+        if scrollbars.contains(Scrollbars::HORIZONTAL) { outer.height.saturating_sub(1) } else { outer.height },
+    )
+}
+
+/// Draws whichever scrollbars are enabled into the space [`reserve_scrollbars()`] carved out.
+///
+/// # Arguments
+/// - `scrollbars`: Which scrollbar(s) are enabled.
+/// - `glyphs`: The glyphs to render the scrollbars with.
+/// - `pos`: The current scroll position (as an x x y pair).
+/// - `outer`: The full outer area, used to compute the buffer stride (see [`scroll()`]).
+/// - `content`: The content area as returned by [`reserve_scrollbars()`].
+/// - `inner`: The size of the _inner_ area (i.e., total area).
+/// - `buf`: The buffer to draw into.
+pub(crate) fn render_scrollbars(scrollbars: Scrollbars, glyphs: ScrollbarGlyphs, pos: (u16, u16), outer: Rect, content: Rect, inner: Rect, buf: &mut Buffer) {
+    if scrollbars.contains(Scrollbars::VERTICAL) {
+        let (thumb_len, thumb_pos) = thumb_metrics(content.height, inner.height, pos.1);
+        let track: Rect = Rect::new(content.x + content.width, content.y, 1, content.height);
+        render_scrollbar(glyphs, outer, track, true, thumb_len, thumb_pos, buf);
+    }
+    if scrollbars.contains(Scrollbars::HORIZONTAL) {
+        let (thumb_len, thumb_pos) = thumb_metrics(content.width, inner.width, pos.0);
+        let track: Rect = Rect::new(content.x, content.y + content.height, content.width, 1);
+        render_scrollbar(glyphs, outer, track, false, thumb_len, thumb_pos, buf);
+    }
+}
+
 
 
 
 
 /***** AUXILLARY *****/
+/// Selects which edges of a [`ScrollArea`] (or [`StatefulScrollArea`]) should render a scrollbar.
+///
+/// Flags can be combined with `|`, e.g. `Scrollbars::VERTICAL | Scrollbars::HORIZONTAL` (which is
+/// also available as [`Scrollbars::BOTH`]).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Scrollbars(u8);
+impl Scrollbars {
+    /// Don't render any scrollbars.
+    pub const NONE: Self = Self(0b00);
+    /// Render a scrollbar along the right edge, reflecting the vertical scroll position.
+    pub const VERTICAL: Self = Self(0b01);
+    /// Render a scrollbar along the bottom edge, reflecting the horizontal scroll position.
+    pub const HORIZONTAL: Self = Self(0b10);
+    /// Render both the vertical and horizontal scrollbars.
+    pub const BOTH: Self = Self(0b11);
+
+    /// Checks whether `self` has (at least) all the flags set that `other` has.
+    ///
+    /// # Arguments
+    /// - `other`: The flag(s) to check for.
+    ///
+    /// # Returns
+    /// True if every flag in `other` is also set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool { self.0 & other.0 == other.0 }
+}
+impl std::ops::BitOr for Scrollbars {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self { Self(self.0 | rhs.0) }
+}
+impl Default for Scrollbars {
+    #[inline]
+    fn default() -> Self { Self::NONE }
+}
+
+
+
+/// The glyphs used to draw a [`ScrollArea`]'s scrollbars.
+///
+/// Construct with [`ScrollbarGlyphs::default()`] for a sensible default, then override whichever
+/// fields should match the caller's theme.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ScrollbarGlyphs {
+    /// The character used for the scrollbar's track (i.e., the part without the thumb).
+    pub track: char,
+    /// The character used for the scrollbar's thumb (i.e., the part indicating the current view).
+    pub thumb: char,
+    /// The character drawn at the top end of a vertical scrollbar's track.
+    pub arrow_up: char,
+    /// The character drawn at the bottom end of a vertical scrollbar's track.
+    pub arrow_down: char,
+    /// The character drawn at the left end of a horizontal scrollbar's track.
+    pub arrow_left: char,
+    /// The character drawn at the right end of a horizontal scrollbar's track.
+    pub arrow_right: char,
+    /// Whether to draw the arrow glyphs at all. When `false`, the track/thumb span the whole
+    /// length of the scrollbar.
+    pub show_arrows: bool,
+}
+impl ScrollbarGlyphs {
+    /// The default glyph set: a thin track, a solid thumb, and triangular arrows at both ends.
+    pub const DEFAULT: Self =
+        Self { track: '│', thumb: '█', arrow_up: '▲', arrow_down: '▼', arrow_left: '◄', arrow_right: '►', show_arrows: true };
+}
+impl Default for ScrollbarGlyphs {
+    #[inline]
+    fn default() -> Self { Self::DEFAULT }
+}
+
+
+
 /// The state that keeps track of the current scroll position of a [`ScrollArea`].
 ///
 /// This version assumes that no widget state is kept (i.e., the state is [`()`]).
@@ -67,6 +241,17 @@ pub type ScrollState = StatefulScrollState<()>;
 pub struct StatefulScrollState<S> {
     /// The coordinates that offset the scroll area (as an x x y pair).
     pos:   (u16, u16),
+    /// The size of the outer (i.e., visible) area as of the last render, as a width x height pair.
+    outer: (u16, u16),
+    /// The size of the inner (i.e., total content) area as of the last render, as a width x height pair.
+    inner: (u16, u16),
+    /// The number of lines/characters a single mouse wheel notch scrolls by.
+    wheel_step: u16,
+    /// Whether a drag starting anywhere in the area scrolls it (`true`), or only a drag starting
+    /// on the scrollbar track does (`false`).
+    drag_anywhere: bool,
+    /// The pointer position of the previous mouse event in an ongoing drag, if any.
+    drag_anchor: Option<(u16, u16)>,
     /// The nested state to pass to the ScrollArea.
     state: S,
 }
@@ -74,7 +259,9 @@ pub struct StatefulScrollState<S> {
 // Constructors
 impl<S: Default> Default for StatefulScrollState<S> {
     #[inline]
-    fn default() -> Self { Self { pos: (0, 0), state: Default::default() } }
+    fn default() -> Self {
+        Self { pos: (0, 0), outer: (0, 0), inner: (0, 0), wheel_step: 3, drag_anywhere: true, drag_anchor: None, state: Default::default() }
+    }
 }
 impl<S> StatefulScrollState<S> {
     /// Constructs a new StatefulScrollState.
@@ -85,7 +272,9 @@ impl<S> StatefulScrollState<S> {
     /// # Returns
     /// A new StatefulScrollState ready for keeping track of scroll states.
     #[inline]
-    pub const fn new(state: S) -> Self { Self { pos: (0, 0), state } }
+    pub const fn new(state: S) -> Self {
+        Self { pos: (0, 0), outer: (0, 0), inner: (0, 0), wheel_step: 3, drag_anywhere: true, drag_anchor: None, state }
+    }
 }
 
 // Scrolling
@@ -138,7 +327,7 @@ impl<S> StatefulScrollState<S> {
     /// A mutable reference to Self for chaining.
     #[inline]
     pub const fn scroll_right_by(&mut self, n: u16) -> &mut Self {
-        self.pos.0 = self.pos.0.saturating_add(n);
+        self.pos.0 = self.pos.0.saturating_add(n).min(self.max_scroll().0);
         self
     }
 
@@ -159,7 +348,7 @@ impl<S> StatefulScrollState<S> {
     /// A mutable reference to Self for chaining.
     #[inline]
     pub const fn scroll_down_by(&mut self, n: u16) -> &mut Self {
-        self.pos.1 = self.pos.1.saturating_add(n);
+        self.pos.1 = self.pos.1.saturating_add(n).min(self.max_scroll().1);
         self
     }
 
@@ -185,6 +374,200 @@ impl<S> StatefulScrollState<S> {
     }
 }
 
+// Targeting
+impl<S> StatefulScrollState<S> {
+    /// Scrolls directly to the given (content-space) coordinates, clamped against content bounds.
+    ///
+    /// # Arguments
+    /// - `x`: The horizontal offset to scroll to.
+    /// - `y`: The vertical offset to scroll to.
+    ///
+    /// # Returns
+    /// A mutable reference to Self for chaining.
+    #[inline]
+    pub const fn scroll_to(&mut self, x: u16, y: u16) -> &mut Self {
+        let max: (u16, u16) = self.max_scroll();
+        self.pos.0 = if x > max.0 { max.0 } else { x };
+        self.pos.1 = if y > max.1 { max.1 } else { y };
+        self
+    }
+
+    /// Scrolls by the minimum amount needed to bring the given (content-space) rectangle fully
+    /// into view, e.g. to keep a selected row visible. Leaves `pos` unchanged if `rect` already
+    /// fits within the last-rendered outer viewport.
+    ///
+    /// # Arguments
+    /// - `rect`: The content-space rectangle that should become fully visible.
+    ///
+    /// # Returns
+    /// A mutable reference to Self for chaining.
+    #[inline]
+    pub const fn scroll_into_view(&mut self, rect: Rect) -> &mut Self {
+        let outer: (u16, u16) = self.outer;
+
+        if rect.x < self.pos.0 {
+            self.pos.0 = rect.x;
+        } else if rect.x + rect.width > self.pos.0 + outer.0 {
+            self.pos.0 = (rect.x + rect.width).saturating_sub(outer.0);
+        }
+
+        if rect.y < self.pos.1 {
+            self.pos.1 = rect.y;
+        } else if rect.y + rect.height > self.pos.1 + outer.1 {
+            self.pos.1 = (rect.y + rect.height).saturating_sub(outer.1);
+        }
+
+        // Re-clamp against content bounds, in case `rect` itself reaches past them.
+        let max: (u16, u16) = self.max_scroll();
+        self.pos.0 = if self.pos.0 > max.0 { max.0 } else { self.pos.0 };
+        self.pos.1 = if self.pos.1 > max.1 { max.1 } else { self.pos.1 };
+        self
+    }
+}
+
+// Position
+impl<S> StatefulScrollState<S> {
+    /// Returns the current scroll position.
+    ///
+    /// # Returns
+    /// The current `pos`, as an x x y pair.
+    #[inline]
+    pub const fn pos(&self) -> (u16, u16) { self.pos }
+}
+
+// Bounds
+impl<S> StatefulScrollState<S> {
+    /// Records the outer/inner extents of the most recent render, so that subsequent `scroll_*`
+    /// calls can clamp against the actual content size instead of growing unboundedly.
+    ///
+    /// # Arguments
+    /// - `outer`: The size of the outer (visible) area that was rendered to, as a width x height pair.
+    /// - `inner`: The size of the inner (total content) area that was rendered, as a width x height pair.
+    #[inline]
+    pub(crate) const fn set_extents(&mut self, outer: (u16, u16), inner: (u16, u16)) {
+        self.outer = outer;
+        self.inner = inner;
+    }
+
+    /// Returns the maximum scroll offset along each axis, given the extents recorded by the last render.
+    ///
+    /// # Returns
+    /// The maximum `(x, y)` offset `pos` can take before the outer area would show blank space
+    /// past the content, i.e. `inner - outer` per axis (clamped at `0`).
+    #[inline]
+    pub const fn max_scroll(&self) -> (u16, u16) { (self.inner.0.saturating_sub(self.outer.0), self.inner.1.saturating_sub(self.outer.1)) }
+
+    /// Checks whether the scroll area is scrolled all the way to the bottom.
+    ///
+    /// # Returns
+    /// True if `pos.1` is at (or past) [`Self::max_scroll()`]'s vertical component.
+    #[inline]
+    pub const fn at_bottom(&self) -> bool { self.pos.1 >= self.max_scroll().1 }
+
+    /// Checks whether the scroll area is scrolled all the way to the right.
+    ///
+    /// # Returns
+    /// True if `pos.0` is at (or past) [`Self::max_scroll()`]'s horizontal component.
+    #[inline]
+    pub const fn at_end(&self) -> bool { self.pos.0 >= self.max_scroll().0 }
+}
+
+// Mouse
+impl<S> StatefulScrollState<S> {
+    /// Sets how many lines/characters a single mouse wheel notch scrolls by.
+    ///
+    /// # Arguments
+    /// - `step`: The new wheel step. Defaults to `3`.
+    ///
+    /// # Returns
+    /// A mutable reference to Self for chaining.
+    #[inline]
+    pub const fn set_wheel_step(&mut self, step: u16) -> &mut Self {
+        self.wheel_step = step;
+        self
+    }
+
+    /// Sets whether a drag can start anywhere in the area, or only on the scrollbar track.
+    ///
+    /// # Arguments
+    /// - `anywhere`: Whether a drag starting anywhere in the area scrolls it (`true`, the
+    ///   default), or only a drag starting on the rightmost column/bottom row (where a scrollbar
+    ///   would be drawn) does (`false`).
+    ///
+    /// # Returns
+    /// A mutable reference to Self for chaining.
+    #[inline]
+    pub const fn set_drag_anywhere(&mut self, anywhere: bool) -> &mut Self {
+        self.drag_anywhere = anywhere;
+        self
+    }
+
+    /// Processes a crossterm mouse event, updating `pos` in response to wheel and drag gestures.
+    ///
+    /// This mirrors kas' `ScrollComponent`, which supports scrolling via both the mouse wheel and
+    /// a click/touch drag.
+    ///
+    /// # Arguments
+    /// - `event`: The mouse event to process.
+    /// - `outer`: The outer (visible) area this scroll area was last rendered to. Used to check
+    ///   whether the event falls within the area, and to turn drag deltas into a proportional
+    ///   content-space offset.
+    ///
+    /// # Returns
+    /// True if the event was handled (and `pos` may have changed), false if it was ignored.
+    pub fn handle_mouse(&mut self, event: MouseEvent, outer: Rect) -> bool {
+        let within: bool = event.column >= outer.x
+            && event.column < outer.x + outer.width
+            && event.row >= outer.y
+            && event.row < outer.y + outer.height;
+        let on_track: bool =
+            event.column == outer.x + outer.width.saturating_sub(1) || event.row == outer.y + outer.height.saturating_sub(1);
+
+        match event.kind {
+            MouseEventKind::ScrollUp if within => {
+                self.scroll_up_by(self.wheel_step);
+                true
+            }
+            MouseEventKind::ScrollDown if within => {
+                self.scroll_down_by(self.wheel_step);
+                true
+            }
+            MouseEventKind::ScrollLeft if within => {
+                self.scroll_left_by(self.wheel_step);
+                true
+            }
+            MouseEventKind::ScrollRight if within => {
+                self.scroll_right_by(self.wheel_step);
+                true
+            }
+
+            MouseEventKind::Down(MouseButton::Left) if within && (self.drag_anywhere || on_track) => {
+                self.drag_anchor = Some((event.column, event.row));
+                true
+            }
+            MouseEventKind::Drag(MouseButton::Left) => match self.drag_anchor {
+                Some((anchor_x, anchor_y)) => {
+                    let max: (u16, u16) = self.max_scroll();
+                    let dx: i32 = event.column as i32 - anchor_x as i32;
+                    let dy: i32 = event.row as i32 - anchor_y as i32;
+                    if outer.width > 0 {
+                        self.pos.0 = (self.pos.0 as i32 + dx * max.0 as i32 / outer.width as i32).clamp(0, max.0 as i32) as u16;
+                    }
+                    if outer.height > 0 {
+                        self.pos.1 = (self.pos.1 as i32 + dy * max.1 as i32 / outer.height as i32).clamp(0, max.1 as i32) as u16;
+                    }
+                    self.drag_anchor = Some((event.column, event.row));
+                    true
+                }
+                None => false,
+            },
+            MouseEventKind::Up(MouseButton::Left) => self.drag_anchor.take().is_some(),
+
+            _ => false,
+        }
+    }
+}
+
 // State
 impl<S> StatefulScrollState<S> {
     /// Provides read-only access to the inner scroll state.
@@ -226,6 +609,10 @@ pub struct ScrollArea<W> {
     widget: W,
     /// The scrolled area, e.g., the size of the thing we're rendering (as a width x height pair).
     inner:  (u16, u16),
+    /// Which scrollbars (if any) to render alongside the content.
+    scrollbars: Scrollbars,
+    /// The glyphs to use when rendering scrollbars.
+    glyphs: ScrollbarGlyphs,
 }
 impl<W> ScrollArea<W> {
     /// Constructs a new ScrollArea.
@@ -239,20 +626,58 @@ impl<W> ScrollArea<W> {
     /// # Returns
     /// A new ScrollArea that can be rendered.
     #[inline]
-    pub const fn new(widget: W, inner: (u16, u16)) -> Self { Self { widget, inner } }
+    pub const fn new(widget: W, inner: (u16, u16)) -> Self {
+        Self { widget, inner, scrollbars: Scrollbars::NONE, glyphs: ScrollbarGlyphs::DEFAULT }
+    }
+
+    /// Opts this ScrollArea into rendering one or more scrollbars alongside its content.
+    ///
+    /// # Arguments
+    /// - `scrollbars`: Which edge(s) to render a scrollbar on, e.g. `Scrollbars::VERTICAL` or
+    ///   `Scrollbars::BOTH`.
+    ///
+    /// # Returns
+    /// Self for chaining.
+    #[inline]
+    pub const fn with_scrollbars(mut self, scrollbars: Scrollbars) -> Self {
+        self.scrollbars = scrollbars;
+        self
+    }
+
+    /// Overrides the glyphs used to draw this ScrollArea's scrollbars.
+    ///
+    /// # Arguments
+    /// - `glyphs`: The track/thumb characters to use instead of the defaults.
+    ///
+    /// # Returns
+    /// Self for chaining.
+    #[inline]
+    pub const fn with_scrollbar_glyphs(mut self, glyphs: ScrollbarGlyphs) -> Self {
+        self.glyphs = glyphs;
+        self
+    }
 }
 impl<W: Widget> StatefulWidget for ScrollArea<W> {
     type State = ScrollState;
 
     #[inline]
     fn render(self, outer: Rect, outer_buf: &mut Buffer, state: &mut Self::State) {
+        // Reserve space for the scrollbars (if any) before carving out the content area.
+        let content: Rect = reserve_scrollbars(outer, self.scrollbars);
+
+        // Remember the extents of this render so future `scroll_*_by` calls can clamp against them.
+        state.set_extents((content.width, content.height), self.inner);
+
         // Render the given widget to a buffer the size of the inner area first.
         let inner: Rect = Rect::new(0, 0, self.inner.0, self.inner.1);
         let mut inner_buf = Buffer::empty(inner);
         self.widget.render(inner, &mut inner_buf);
 
         // Run the math
-        scroll(state.pos, outer, inner, &inner_buf, outer_buf);
+        scroll(state.pos, outer, content, inner, &inner_buf, outer_buf);
+
+        // Finally, draw the scrollbars over the reserved space.
+        render_scrollbars(self.scrollbars, self.glyphs, state.pos, outer, content, inner, outer_buf);
     }
 }
 
@@ -270,6 +695,10 @@ pub struct StatefulScrollArea<W> {
     widget: W,
     /// The scrolled area, e.g., the size of the thing we're rendering (as a width x height pair).
     inner:  (u16, u16),
+    /// Which scrollbars (if any) to render alongside the content.
+    scrollbars: Scrollbars,
+    /// The glyphs to use when rendering scrollbars.
+    glyphs: ScrollbarGlyphs,
 }
 impl<W> StatefulScrollArea<W> {
     /// Constructs a new StatefulScrollArea.
@@ -283,19 +712,325 @@ impl<W> StatefulScrollArea<W> {
     /// # Returns
     /// A new StatefulScrollArea that can be rendered.
     #[inline]
-    pub const fn new(widget: W, inner: (u16, u16)) -> Self { Self { widget, inner } }
+    pub const fn new(widget: W, inner: (u16, u16)) -> Self {
+        Self { widget, inner, scrollbars: Scrollbars::NONE, glyphs: ScrollbarGlyphs::DEFAULT }
+    }
+
+    /// Opts this StatefulScrollArea into rendering one or more scrollbars alongside its content.
+    ///
+    /// # Arguments
+    /// - `scrollbars`: Which edge(s) to render a scrollbar on, e.g. `Scrollbars::VERTICAL` or
+    ///   `Scrollbars::BOTH`.
+    ///
+    /// # Returns
+    /// Self for chaining.
+    #[inline]
+    pub const fn with_scrollbars(mut self, scrollbars: Scrollbars) -> Self {
+        self.scrollbars = scrollbars;
+        self
+    }
+
+    /// Overrides the glyphs used to draw this StatefulScrollArea's scrollbars.
+    ///
+    /// # Arguments
+    /// - `glyphs`: The track/thumb characters to use instead of the defaults.
+    ///
+    /// # Returns
+    /// Self for chaining.
+    #[inline]
+    pub const fn with_scrollbar_glyphs(mut self, glyphs: ScrollbarGlyphs) -> Self {
+        self.glyphs = glyphs;
+        self
+    }
 }
 impl<W: StatefulWidget> StatefulWidget for StatefulScrollArea<W> {
     type State = StatefulScrollState<W::State>;
 
     #[inline]
     fn render(self, outer: Rect, outer_buf: &mut Buffer, state: &mut Self::State) {
+        // Reserve space for the scrollbars (if any) before carving out the content area.
+        let content: Rect = reserve_scrollbars(outer, self.scrollbars);
+
+        // Remember the extents of this render so future `scroll_*_by` calls can clamp against them.
+        state.set_extents((content.width, content.height), self.inner);
+
         // Render the given widget to a buffer the size of the inner area first.
         let inner: Rect = Rect::new(0, 0, self.inner.0, self.inner.1);
         let mut inner_buf = Buffer::empty(inner);
         self.widget.render(inner, &mut inner_buf, &mut state.state);
 
         // Run the math
-        scroll(state.pos, outer, inner, &inner_buf, outer_buf);
+        scroll(state.pos, outer, content, inner, &inner_buf, outer_buf);
+
+        // Finally, draw the scrollbars over the reserved space.
+        render_scrollbars(self.scrollbars, self.glyphs, state.pos, outer, content, inner, outer_buf);
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thumb_metrics_full_view_fills_track() {
+        // The whole content is visible, so the thumb should span the entire track at offset 0.
+        assert_eq!(thumb_metrics(10, 10, 0), (10, 0));
+    }
+
+    #[test]
+    fn thumb_metrics_scales_with_content_and_tracks_pos() {
+        // Half the content is visible, so the thumb should be half the track length...
+        let (thumb_len, thumb_pos) = thumb_metrics(10, 20, 0);
+        assert_eq!(thumb_len, 5);
+        assert_eq!(thumb_pos, 0);
+
+        // ...and scrolled all the way to the max offset (20 - 10 = 10), the thumb should sit at the
+        // far end of its travel (track len 10 - thumb len 5 = 5).
+        let (thumb_len, thumb_pos) = thumb_metrics(10, 20, 10);
+        assert_eq!(thumb_len, 5);
+        assert_eq!(thumb_pos, 5);
+    }
+
+    #[test]
+    fn thumb_metrics_empty_track_or_content_is_degenerate() {
+        assert_eq!(thumb_metrics(0, 10, 0), (0, 0));
+        assert_eq!(thumb_metrics(10, 0, 0), (0, 0));
+    }
+
+    /// Fills every cell of `buf` with a distinct character per row, so a misrouted row read/write
+    /// shows up unambiguously in a failing assertion.
+    fn fill_rows(buf: &mut Buffer, rect: Rect) {
+        for y in 0..rect.height {
+            let c: char = (b'A' + y as u8) as char;
+            for x in 0..rect.width {
+                buf.content[(y * rect.width + x) as usize].set_char(c);
+            }
+        }
+    }
+
+    #[test]
+    fn scroll_uses_outer_stride_not_window_width() {
+        // `outer` is the real, full frame buffer; `window` is one column narrower, as it would be
+        // once a vertical scrollbar reserves the rightmost column. If `scroll()` ever indexes
+        // `outer_buf` using `window.width` as the stride instead of `outer.width`, every row past
+        // the first lands one cell too early, corrupting the reserved scrollbar column.
+        let outer: Rect = Rect::new(0, 0, 10, 4);
+        let window: Rect = Rect::new(0, 0, 9, 4);
+        let inner: Rect = Rect::new(0, 0, 9, 4);
+
+        let mut inner_buf = Buffer::empty(inner);
+        fill_rows(&mut inner_buf, inner);
+
+        let mut outer_buf = Buffer::empty(outer);
+        scroll((0, 0), outer, window, inner, &inner_buf, &mut outer_buf);
+
+        for y in 0..4 {
+            let expect: char = (b'A' + y as u8) as char;
+            for x in 0..9 {
+                assert_eq!(outer_buf.content[(y * 10 + x) as usize].symbol().chars().next(), Some(expect));
+            }
+            // The reserved scrollbar column must be left untouched, not overwritten with the next
+            // row's content (which is what the stride bug would do).
+            assert_eq!(outer_buf.content[(y * 10 + 9) as usize].symbol(), " ");
+        }
+    }
+
+    #[test]
+    fn scroll_offsets_the_inner_read_by_pos() {
+        // Scrolling must reveal different content, not merely shrink the copied rectangle: reading
+        // `inner_buf` always from (0, 0) regardless of `pos` was the original (now-fixed) bug.
+        let outer: Rect = Rect::new(0, 0, 5, 5);
+        let inner: Rect = Rect::new(0, 0, 5, 10);
+
+        let mut inner_buf = Buffer::empty(inner);
+        fill_rows(&mut inner_buf, inner);
+
+        let mut outer_buf = Buffer::empty(outer);
+        scroll((0, 3), outer, outer, inner, &inner_buf, &mut outer_buf);
+
+        for y in 0..5 {
+            let expect: char = (b'A' + 3 + y as u8) as char;
+            assert_eq!(outer_buf.content[(y * 5) as usize].symbol().chars().next(), Some(expect));
+        }
+    }
+
+    #[test]
+    fn render_scrollbar_draws_arrows_at_track_ends() {
+        let outer: Rect = Rect::new(0, 0, 1, 6);
+        let track: Rect = Rect::new(0, 0, 1, 6);
+        let mut buf = Buffer::empty(outer);
+
+        render_scrollbar(ScrollbarGlyphs::DEFAULT, outer, track, true, 2, 2, &mut buf);
+
+        assert_eq!(buf.content[0].symbol().chars().next(), Some(ScrollbarGlyphs::DEFAULT.arrow_up));
+        assert_eq!(buf.content[5].symbol().chars().next(), Some(ScrollbarGlyphs::DEFAULT.arrow_down));
+        // Cells between the arrows and the thumb are plain track.
+        assert_eq!(buf.content[1].symbol().chars().next(), Some(ScrollbarGlyphs::DEFAULT.track));
+        // The thumb occupies rows [2, 4).
+        assert_eq!(buf.content[2].symbol().chars().next(), Some(ScrollbarGlyphs::DEFAULT.thumb));
+        assert_eq!(buf.content[3].symbol().chars().next(), Some(ScrollbarGlyphs::DEFAULT.thumb));
+        assert_eq!(buf.content[4].symbol().chars().next(), Some(ScrollbarGlyphs::DEFAULT.track));
+    }
+
+    #[test]
+    fn render_scrollbar_without_arrows_spans_full_track() {
+        let glyphs = ScrollbarGlyphs { show_arrows: false, ..ScrollbarGlyphs::DEFAULT };
+        let outer: Rect = Rect::new(0, 0, 1, 4);
+        let track: Rect = Rect::new(0, 0, 1, 4);
+        let mut buf = Buffer::empty(outer);
+
+        render_scrollbar(glyphs, outer, track, true, 4, 0, &mut buf);
+
+        for i in 0..4 {
+            assert_eq!(buf.content[i].symbol().chars().next(), Some(glyphs.thumb));
+        }
+    }
+
+    #[test]
+    fn max_scroll_is_inner_minus_outer() {
+        let mut state = ScrollState::default();
+        state.set_extents((10, 5), (30, 20));
+        assert_eq!(state.max_scroll(), (20, 15));
+    }
+
+    #[test]
+    fn max_scroll_clamps_at_zero_when_outer_exceeds_inner() {
+        let mut state = ScrollState::default();
+        state.set_extents((30, 20), (10, 5));
+        assert_eq!(state.max_scroll(), (0, 0));
+    }
+
+    #[test]
+    fn scroll_down_by_and_right_by_clamp_to_max_scroll() {
+        let mut state = ScrollState::default();
+        state.set_extents((10, 5), (30, 20));
+
+        state.scroll_down_by(1000);
+        state.scroll_right_by(1000);
+        assert_eq!(state.pos(), state.max_scroll());
+        assert!(state.at_bottom());
+        assert!(state.at_end());
+    }
+
+    #[test]
+    fn at_bottom_and_at_end_are_false_until_max_scroll_reached() {
+        let mut state = ScrollState::default();
+        state.set_extents((10, 5), (30, 20));
+
+        state.scroll_down_by(1);
+        state.scroll_right_by(1);
+        assert!(!state.at_bottom());
+        assert!(!state.at_end());
+
+        state.scroll_down_by(14);
+        state.scroll_right_by(19);
+        assert!(state.at_bottom());
+        assert!(state.at_end());
+    }
+
+    /// Builds a [`MouseEvent`] of the given kind at the given position, with no modifiers held.
+    fn mouse_event(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent { kind, column, row, modifiers: crossterm::event::KeyModifiers::NONE }
+    }
+
+    #[test]
+    fn handle_mouse_wheel_scrolls_within_area_only() {
+        let mut state = ScrollState::default();
+        state.set_extents((10, 5), (30, 20));
+        let outer: Rect = Rect::new(0, 0, 10, 5);
+
+        assert!(state.handle_mouse(mouse_event(MouseEventKind::ScrollDown, 2, 2), outer));
+        assert_eq!(state.pos(), (0, 3));
+
+        // Outside the area, the event is ignored and `pos` is untouched.
+        assert!(!state.handle_mouse(mouse_event(MouseEventKind::ScrollDown, 20, 20), outer));
+        assert_eq!(state.pos(), (0, 3));
+    }
+
+    #[test]
+    fn handle_mouse_drag_moves_pos_proportionally() {
+        let mut state = ScrollState::default();
+        state.set_extents((10, 10), (30, 30));
+        let outer: Rect = Rect::new(0, 0, 10, 10);
+
+        assert!(state.handle_mouse(mouse_event(MouseEventKind::Down(MouseButton::Left), 5, 5), outer));
+        // max_scroll() is (20, 20); dragging 5 cells (half the outer area) should move pos by half
+        // of max_scroll along each axis.
+        assert!(state.handle_mouse(mouse_event(MouseEventKind::Drag(MouseButton::Left), 10, 10), outer));
+        assert_eq!(state.pos(), (10, 10));
+
+        assert!(state.handle_mouse(mouse_event(MouseEventKind::Up(MouseButton::Left), 10, 10), outer));
+        // With the drag released, a further `Drag` event has no anchor to work from.
+        assert!(!state.handle_mouse(mouse_event(MouseEventKind::Drag(MouseButton::Left), 0, 0), outer));
+        assert_eq!(state.pos(), (10, 10));
+    }
+
+    #[test]
+    fn handle_mouse_drag_requires_track_when_drag_anywhere_disabled() {
+        let mut state = ScrollState::default();
+        state.set_extents((10, 10), (30, 30));
+        state.set_drag_anywhere(false);
+        let outer: Rect = Rect::new(0, 0, 10, 10);
+
+        // Not on the rightmost column/bottom row, so the drag doesn't start.
+        assert!(!state.handle_mouse(mouse_event(MouseEventKind::Down(MouseButton::Left), 5, 5), outer));
+        // On the bottom row (part of the track), so it does.
+        assert!(state.handle_mouse(mouse_event(MouseEventKind::Down(MouseButton::Left), 5, 9), outer));
+    }
+
+    #[test]
+    fn scroll_to_clamps_to_max_scroll() {
+        let mut state = ScrollState::default();
+        state.set_extents((10, 5), (30, 20));
+
+        state.scroll_to(5, 3);
+        assert_eq!(state.pos(), (5, 3));
+
+        state.scroll_to(1000, 1000);
+        assert_eq!(state.pos(), state.max_scroll());
+    }
+
+    #[test]
+    fn scroll_into_view_leaves_pos_unchanged_when_already_visible() {
+        let mut state = ScrollState::default();
+        state.set_extents((10, 10), (30, 30));
+        state.scroll_to(5, 5);
+
+        // Fully within the viewport [5, 15) x [5, 15).
+        state.scroll_into_view(Rect::new(6, 6, 2, 2));
+        assert_eq!(state.pos(), (5, 5));
+    }
+
+    #[test]
+    fn scroll_into_view_scrolls_backward_when_rect_is_above_or_left() {
+        let mut state = ScrollState::default();
+        state.set_extents((10, 10), (30, 30));
+        state.scroll_to(10, 10);
+
+        state.scroll_into_view(Rect::new(2, 3, 1, 1));
+        assert_eq!(state.pos(), (2, 3));
+    }
+
+    #[test]
+    fn scroll_into_view_scrolls_forward_when_rect_is_below_or_right() {
+        let mut state = ScrollState::default();
+        state.set_extents((10, 10), (30, 30));
+
+        // Rect's bottom-right corner (25, 25) is past the viewport [0, 10) x [0, 10); pos should
+        // move just enough that the rect's far edge lines up with the viewport's far edge.
+        state.scroll_into_view(Rect::new(24, 24, 1, 1));
+        assert_eq!(state.pos(), (15, 15));
+    }
+
+    #[test]
+    fn scroll_into_view_reclamps_when_rect_exceeds_content_bounds() {
+        let mut state = ScrollState::default();
+        state.set_extents((10, 10), (30, 30));
+
+        // A rect reaching past the content's own bounds must not push `pos` past `max_scroll()`.
+        state.scroll_into_view(Rect::new(25, 25, 10, 10));
+        assert_eq!(state.pos(), state.max_scroll());
     }
 }
@@ -0,0 +1,223 @@
+//  SCROLLBACK AREA.rs
+//    by Lut99
+//
+//  Created:
+//    30 Jul 2026, 10:30:00
+//  Last edited:
+//    30 Jul 2026, 10:30:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements a bounded ring buffer of pre-styled lines, suitable for
+//!   backing a growing log/terminal view.
+//
+
+use std::collections::VecDeque;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::Line;
+use ratatui::widgets::Widget;
+
+
+/***** LIBRARY *****/
+/// A bounded ring buffer of pre-styled [`Line`]s, suitable for backing a growing log/terminal
+/// view without requiring the caller to own a widget whose height equals the full scrollback.
+///
+/// Like alacritty's `Grid` scrollback storage, pushing a line past the `scrollback` capacity
+/// evicts the oldest one. A `display_offset` pins the viewport to the bottom while the user is
+/// "live" (see [`ScrollbackArea::following()`]), but freezes the visible window once they've
+/// scrolled up, so incoming lines don't yank the view out from under them.
+#[derive(Debug, Clone)]
+pub struct ScrollbackArea {
+    /// The buffered lines, oldest first.
+    lines: VecDeque<Line<'static>>,
+    /// The maximum number of lines to retain before the oldest ones are evicted.
+    scrollback: usize,
+    /// How many lines up from the bottom the viewport is currently showing.
+    display_offset: usize,
+    /// Whether the viewport is pinned to the bottom (`true`) or has been scrolled up (`false`).
+    following: bool,
+}
+impl ScrollbackArea {
+    /// Constructs a new, empty ScrollbackArea.
+    ///
+    /// # Arguments
+    /// - `scrollback`: The maximum number of lines to retain before the oldest lines get evicted.
+    ///
+    /// # Returns
+    /// A new ScrollbackArea ready to have lines pushed onto it.
+    #[inline]
+    pub fn new(scrollback: usize) -> Self { Self { lines: VecDeque::with_capacity(scrollback), scrollback, display_offset: 0, following: true } }
+
+    /// Pushes a new line onto the back of the scrollback, evicting the oldest line once at capacity.
+    ///
+    /// If the viewport is currently [`following()`](ScrollbackArea::following), it stays pinned to
+    /// the bottom; otherwise, the frozen viewport is kept pointing at the same lines.
+    ///
+    /// # Arguments
+    /// - `line`: The (already styled) line to append.
+    pub fn push_line(&mut self, line: Line<'static>) {
+        if self.lines.len() >= self.scrollback {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+
+        // Every push moves each buffered line one further from the bottom (whether or not an
+        // eviction happened), and `display_offset` counts from the bottom, so to keep the same
+        // lines visible while frozen, it grows by one per push (capped at the oldest line).
+        if !self.following {
+            self.display_offset = (self.display_offset + 1).min(self.lines.len().saturating_sub(1));
+        }
+    }
+
+    /// Scrolls the viewport up (further into the past) by `n` lines, un-pinning it from the bottom.
+    ///
+    /// # Arguments
+    /// - `n`: The number of lines to scroll up.
+    pub fn scroll_up_by(&mut self, n: usize) {
+        self.display_offset = (self.display_offset + n).min(self.lines.len().saturating_sub(1));
+        self.following = false;
+    }
+
+    /// Scrolls the viewport down (back towards the present) by `n` lines.
+    ///
+    /// Resumes following once the viewport reaches the bottom.
+    ///
+    /// # Arguments
+    /// - `n`: The number of lines to scroll down.
+    pub fn scroll_down_by(&mut self, n: usize) {
+        self.display_offset = self.display_offset.saturating_sub(n);
+        if self.display_offset == 0 {
+            self.following = true;
+        }
+    }
+
+    /// Scrolls the viewport all the way back to the bottom and resumes following new lines.
+    #[inline]
+    pub fn scroll_to_bottom(&mut self) {
+        self.display_offset = 0;
+        self.following = true;
+    }
+
+    /// Returns whether the viewport is currently pinned to the bottom.
+    ///
+    /// # Returns
+    /// True if newly-pushed lines are immediately visible, false if the user has scrolled up and
+    /// the viewport is frozen.
+    #[inline]
+    pub const fn following(&self) -> bool { self.following }
+
+    /// Returns the number of lines currently buffered.
+    ///
+    /// # Returns
+    /// The number of buffered lines, at most the `scrollback` capacity passed to [`Self::new()`].
+    #[inline]
+    pub fn len(&self) -> usize { self.lines.len() }
+
+    /// Returns whether no lines have been pushed yet.
+    ///
+    /// # Returns
+    /// True if [`Self::len()`] is `0`.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.lines.is_empty() }
+
+    /// Computes the `[start, end)` indices of the lines currently visible in a viewport of the
+    /// given height.
+    ///
+    /// # Arguments
+    /// - `height`: The height (in rows) of the viewport to compute the window for.
+    ///
+    /// # Returns
+    /// The `[start, end)` range into the buffered lines that should be rendered.
+    fn window(&self, height: usize) -> (usize, usize) {
+        let end: usize = self.lines.len().saturating_sub(self.display_offset);
+        let start: usize = end.saturating_sub(height);
+        (start, end)
+    }
+}
+impl Widget for &ScrollbackArea {
+    #[inline]
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Render only the window `[len - offset - area.height .. len - offset]`.
+        let (start, end) = self.window(area.height as usize);
+        for (i, line) in self.lines.range(start..end).enumerate() {
+            line.clone().render(Rect::new(area.x, area.y + i as u16, area.width, 1), buf);
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reconstructs the plain text of a line by concatenating its spans' content.
+    fn line_text(line: &Line<'static>) -> String { line.spans.iter().map(|span| span.content.as_ref()).collect() }
+
+    /// Returns the plain text of the lines currently visible in a viewport of the given height.
+    fn visible(area: &ScrollbackArea, height: usize) -> Vec<String> {
+        let (start, end) = area.window(height);
+        area.lines.range(start..end).map(line_text).collect()
+    }
+
+    #[test]
+    fn push_past_capacity_evicts_oldest() {
+        let mut area = ScrollbackArea::new(3);
+        for i in 1..=5 {
+            area.push_line(Line::raw(i.to_string()));
+        }
+        assert_eq!(area.len(), 3);
+        assert_eq!(visible(&area, 3), vec!["3", "4", "5"]);
+    }
+
+    #[test]
+    fn push_while_scrolled_keeps_window_frozen() {
+        let mut area = ScrollbackArea::new(100);
+        for i in 1..=10 {
+            area.push_line(Line::raw(i.to_string()));
+        }
+
+        area.scroll_up_by(3);
+        assert!(!area.following());
+        let before = visible(&area, 4);
+
+        // Pushing more lines (without eviction) must not shift the frozen window.
+        area.push_line(Line::raw("11"));
+        area.push_line(Line::raw("12"));
+        assert_eq!(visible(&area, 4), before);
+    }
+
+    #[test]
+    fn push_past_capacity_while_scrolled_keeps_window_frozen() {
+        let mut area = ScrollbackArea::new(5);
+        for i in 1..=5 {
+            area.push_line(Line::raw(i.to_string()));
+        }
+
+        area.scroll_up_by(2);
+        assert!(!area.following());
+        let before = visible(&area, 2);
+
+        // Pushing past capacity (evicting the oldest line) must also leave the frozen window intact.
+        area.push_line(Line::raw("6"));
+        assert_eq!(visible(&area, 2), before);
+    }
+
+    #[test]
+    fn scroll_to_bottom_resumes_following() {
+        let mut area = ScrollbackArea::new(10);
+        for i in 1..=5 {
+            area.push_line(Line::raw(i.to_string()));
+        }
+
+        area.scroll_up_by(2);
+        assert!(!area.following());
+
+        area.scroll_to_bottom();
+        assert!(area.following());
+        assert_eq!(visible(&area, 3), vec!["3", "4", "5"]);
+    }
+}
@@ -0,0 +1,238 @@
+//  VIRTUAL SCROLL AREA.rs
+//    by Lut99
+//
+//  Created:
+//    30 Jul 2026, 10:00:00
+//  Last edited:
+//    30 Jul 2026, 10:00:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements a scroll area that only materializes the visible slice of
+//!   its content, instead of rendering everything every frame.
+//
+
+use std::cmp::min;
+use std::ops::Range;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+use super::scroll_area::{render_scrollbars, reserve_scrollbars, scroll, ScrollState, ScrollbarGlyphs, Scrollbars};
+
+
+/***** LIBRARY *****/
+/// A widget that can render an arbitrary, contiguous range of its rows on demand.
+///
+/// Unlike a regular [`Widget`](ratatui::widgets::Widget), an implementor only has to produce the
+/// rows a [`VirtualScrollArea`] is actually about to show, so content that is far too large (or
+/// expensive) to fully render every frame can still be scrolled cheaply.
+pub trait VirtualWidget {
+    /// Renders the given range of content-space rows.
+    ///
+    /// # Arguments
+    /// - `rows`: The range of row indices (into the widget's total content, as passed to
+    ///   [`VirtualScrollArea::new()`]) to render.
+    /// - `buf`: The buffer to render into. Row `rows.start + i` of the content goes to row `i` of
+    ///   `buf`.
+    fn render_range(&self, rows: Range<u16>, buf: &mut Buffer);
+}
+
+
+
+/// The VirtualScrollArea renders only the visible slice of a (potentially huge) [`VirtualWidget`],
+/// instead of fully materializing its content every frame.
+///
+/// See the [`ScrollArea`](super::ScrollArea) for a variant that always renders the whole content.
+#[derive(Debug, Clone)]
+pub struct VirtualScrollArea<W> {
+    /// The virtualized widget to render a slice of.
+    widget: W,
+    /// The total size of the widget's content (as a width x height pair).
+    inner: (u16, u16),
+    /// The number of extra rows to render above/below the visible window, to soften scrolling.
+    overscan: u16,
+    /// Which scrollbars (if any) to render alongside the content.
+    scrollbars: Scrollbars,
+    /// The glyphs to use when rendering scrollbars.
+    glyphs: ScrollbarGlyphs,
+}
+impl<W> VirtualScrollArea<W> {
+    /// Constructs a new VirtualScrollArea.
+    ///
+    /// # Arguments
+    /// - `widget`: The [`VirtualWidget`] to render a slice of.
+    /// - `inner`: The total size of the widget's content (i.e., the size it would have if it were
+    ///   rendered in full). Given as `(width x height)`.
+    ///
+    /// # Returns
+    /// A new VirtualScrollArea that can be rendered.
+    #[inline]
+    pub const fn new(widget: W, inner: (u16, u16)) -> Self {
+        Self { widget, inner, overscan: 0, scrollbars: Scrollbars::NONE, glyphs: ScrollbarGlyphs::DEFAULT }
+    }
+
+    /// Sets the number of extra rows to materialize above and below the visible window.
+    ///
+    /// # Arguments
+    /// - `overscan`: The number of rows of margin to render on either side of the viewport.
+    ///
+    /// # Returns
+    /// Self for chaining.
+    #[inline]
+    pub const fn with_overscan(mut self, overscan: u16) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Opts this VirtualScrollArea into rendering one or more scrollbars alongside its content.
+    ///
+    /// # Arguments
+    /// - `scrollbars`: Which edge(s) to render a scrollbar on, e.g. `Scrollbars::VERTICAL` or
+    ///   `Scrollbars::BOTH`.
+    ///
+    /// # Returns
+    /// Self for chaining.
+    #[inline]
+    pub const fn with_scrollbars(mut self, scrollbars: Scrollbars) -> Self {
+        self.scrollbars = scrollbars;
+        self
+    }
+
+    /// Overrides the glyphs used to draw this VirtualScrollArea's scrollbars.
+    ///
+    /// # Arguments
+    /// - `glyphs`: The track/thumb characters to use instead of the defaults.
+    ///
+    /// # Returns
+    /// Self for chaining.
+    #[inline]
+    pub const fn with_scrollbar_glyphs(mut self, glyphs: ScrollbarGlyphs) -> Self {
+        self.glyphs = glyphs;
+        self
+    }
+}
+impl<W: VirtualWidget> StatefulWidget for VirtualScrollArea<W> {
+    type State = ScrollState;
+
+    #[inline]
+    fn render(self, outer: Rect, outer_buf: &mut Buffer, state: &mut Self::State) {
+        // Reserve space for the scrollbars (if any) before carving out the content area.
+        let content: Rect = reserve_scrollbars(outer, self.scrollbars);
+
+        // Remember the extents of this render so future `scroll_*_by` calls can clamp against them.
+        state.set_extents((content.width, content.height), self.inner);
+
+        // Only materialize the rows that are actually going to be visible, plus a small overscan
+        // margin on either side. `pos.1` is re-clamped against the *current* `self.inner.1` here,
+        // since `state` only re-clamps on an explicit `scroll_*` call and the content may have
+        // shrunk since the position was last set.
+        let pos: (u16, u16) = state.pos();
+        let y: u16 = min(pos.1, self.inner.1);
+        let start: u16 = y.saturating_sub(self.overscan);
+        let end: u16 = min(self.inner.1, y.saturating_add(content.height).saturating_add(self.overscan));
+        let rows: Range<u16> = start..end;
+
+        let slice: Rect = Rect::new(0, 0, self.inner.0, rows.end - rows.start);
+        let mut slice_buf = Buffer::empty(slice);
+        self.widget.render_range(rows, &mut slice_buf);
+
+        // The slice buffer's own top row is `start`, so the position we ask `scroll()` to cut is
+        // offset by that amount.
+        let slice_pos: (u16, u16) = (pos.0, y - start);
+        scroll(slice_pos, outer, content, slice, &slice_buf, outer_buf);
+
+        // Finally, draw the scrollbars over the reserved space.
+        let inner: Rect = Rect::new(0, 0, self.inner.0, self.inner.1);
+        render_scrollbars(self.scrollbars, self.glyphs, pos, outer, content, inner, outer_buf);
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::StatefulWidget;
+
+    /// A [`VirtualWidget`] whose rows are distinguishable by a single letter derived from the
+    /// absolute row index, so a misrouted or mis-windowed slice shows up as a wrong letter.
+    struct Rows;
+    impl VirtualWidget for Rows {
+        fn render_range(&self, rows: Range<u16>, buf: &mut Buffer) {
+            for (i, row) in rows.enumerate() {
+                let c: char = (b'A' + (row % 26) as u8) as char;
+                for x in 0..buf.area.width {
+                    buf.content[i * buf.area.width as usize + x as usize].set_char(c);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn renders_only_the_visible_window_plus_overscan() {
+        /// Forwards to [`Rows`], remembering the range of rows it was last asked to render.
+        struct Recording<'a>(&'a std::cell::RefCell<Option<Range<u16>>>);
+        impl VirtualWidget for Recording<'_> {
+            fn render_range(&self, rows: Range<u16>, buf: &mut Buffer) {
+                *self.0.borrow_mut() = Some(rows.clone());
+                Rows.render_range(rows, buf);
+            }
+        }
+        let requested: std::cell::RefCell<Option<Range<u16>>> = std::cell::RefCell::new(None);
+
+        let outer: Rect = Rect::new(0, 0, 5, 10);
+        let mut state = ScrollState::default();
+        let mut buf = Buffer::empty(outer);
+
+        // First render establishes `state`'s extents so a later `scroll_to()` clamps correctly.
+        VirtualScrollArea::new(Rows, (5, 100)).with_overscan(2).render(outer, &mut buf, &mut state);
+        state.scroll_to(0, 50);
+
+        let mut buf = Buffer::empty(outer);
+        VirtualScrollArea::new(Recording(&requested), (5, 100)).with_overscan(2).render(outer, &mut buf, &mut state);
+
+        // Only the visible rows 50..60 plus 2 rows of overscan on either side are materialized.
+        assert_eq!(requested.take(), Some(48..62));
+
+        // Scrolled to row 50 with a 10-row viewport: row 0 of the outer buffer shows content row 50.
+        for y in 0..10 {
+            let expect: char = (b'A' + ((50 + y) % 26) as u8) as char;
+            assert_eq!(buf.content[(y * 5) as usize].symbol().chars().next(), Some(expect));
+        }
+    }
+
+    #[test]
+    fn stale_pos_past_shrunk_content_does_not_panic() {
+        let outer: Rect = Rect::new(0, 0, 5, 10);
+        let mut state = ScrollState::default();
+        let mut buf = Buffer::empty(outer);
+
+        // Establish a scroll position deep into a large content area...
+        VirtualScrollArea::new(Rows, (5, 100)).render(outer, &mut buf, &mut state);
+        state.scroll_to(0, 90);
+
+        // ...then render again with content that has since shrunk well below that position. Before
+        // the `y = min(pos.1, self.inner.1)` clamp, `rows.end - rows.start` could underflow here.
+        let mut buf = Buffer::empty(outer);
+        VirtualScrollArea::new(Rows, (5, 3)).render(outer, &mut buf, &mut state);
+    }
+
+    #[test]
+    fn scroll_reserves_scrollbar_column_without_corrupting_it() {
+        let outer: Rect = Rect::new(0, 0, 6, 5);
+        let mut state = ScrollState::default();
+        let mut buf = Buffer::empty(outer);
+
+        VirtualScrollArea::new(Rows, (5, 100)).with_scrollbars(Scrollbars::VERTICAL).render(outer, &mut buf, &mut state);
+
+        for y in 0..5 {
+            let expect: char = (b'A' + (y % 26) as u8) as char;
+            for x in 0..5 {
+                assert_eq!(buf.content[(y * 6 + x) as usize].symbol().chars().next(), Some(expect));
+            }
+        }
+    }
+}